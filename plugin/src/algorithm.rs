@@ -30,6 +30,54 @@ fn record_optimal_directions(candidates: &[(u8, f32, f32)]) -> u8 {
         .fold(0u8, |acc, (bit, _, _)| acc | bit)
 }
 
+/// Records traceback directions for a cell, keeping every transition that
+/// achieves the best score. Unlike [`record_optimal_directions`], this does
+/// not narrow further by predecessor score: the Gotoh recurrence's M/X/Y
+/// split means two transitions can reach the same cell value through
+/// predecessors with different scores, and both are equally optimal, so
+/// affine traceback uses this to enumerate all co-optimal alignments.
+fn record_all_optimal_directions(candidates: &[(u8, f32, f32)]) -> u8 {
+    if candidates.is_empty() {
+        return 0;
+    }
+
+    let best_transition = candidates.iter().map(|(_, val, _)| *val).reduce(f32::max).unwrap();
+
+    candidates
+        .iter()
+        .filter(|(_, val, _)| *val == best_transition)
+        .fold(0u8, |acc, (bit, _, _)| acc | bit)
+}
+
+/// Classifies a single aligned column for the BLAST-style `match_line`:
+/// `|` for an identical pair, `:` for a strongly positive-scoring mismatch,
+/// `.` for a weakly positive-scoring mismatch, and a space for a gap or a
+/// non-positive-scoring mismatch.
+fn match_symbol(c1: char, c2: char, column_score: f32) -> char {
+    if c1 == '-' || c2 == '-' {
+        ' '
+    } else if c1.to_ascii_uppercase() == c2.to_ascii_uppercase() {
+        '|'
+    } else if column_score > 1.0 {
+        ':'
+    } else if column_score > 0.0 {
+        '.'
+    } else {
+        ' '
+    }
+}
+
+/// Builds the `column_scores` and `match_line` outputs from an ordered
+/// sequence of (seq1 char, seq2 char, column score) triples.
+fn build_conservation_track(columns: &[(char, char, f32)]) -> (Vec<f32>, String) {
+    let column_scores = columns.iter().map(|(_, _, score)| *score).collect();
+    let match_line = columns
+        .iter()
+        .map(|&(c1, c2, score)| match_symbol(c1, c2, score))
+        .collect();
+    (column_scores, match_line)
+}
+
 /// Classic Needleman-Wunsch algorithm with linear gap penalty.
 pub fn needleman_wunsch_linear(
     seq1: &str,
@@ -92,11 +140,15 @@ pub fn needleman_wunsch_linear(
 
     let mut alignments = Vec::new();
     find_all_paths_linear(
+        n,
+        m,
         n,
         m,
         &directions,
         &seq1,
         &seq2,
+        scorer,
+        gap_penalty,
         &mut Vec::new(),
         &mut alignments,
         max_paths,
@@ -105,20 +157,23 @@ pub fn needleman_wunsch_linear(
     AlignmentOutput {
         score: matrix[n][m],
         alignments,
-        matrices: Matrices {
-            m: matrix,
-        },
+        matrices: Some(Matrices { m: matrix }),
     }
 }
 
 /// Recursive traceback for linear gap penalty mode.
+#[allow(clippy::too_many_arguments)]
 fn find_all_paths_linear(
     i: usize,
     j: usize,
+    end_i: usize,
+    end_j: usize,
     directions: &[Vec<u8>],
     seq1: &[char],
     seq2: &[char],
-    current_path: &mut Vec<(usize, usize, char, char)>,
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+    current_path: &mut Vec<(usize, usize, char, char, f32)>,
     results: &mut Vec<Alignment>,
     max_paths: usize,
 ) {
@@ -131,17 +186,28 @@ fn find_all_paths_linear(
         let mut aligned1 = String::new();
         let mut aligned2 = String::new();
         let mut path = vec![PathStep { i: 0, j: 0 }];
+        let mut columns = Vec::with_capacity(current_path.len());
 
-        for &(r, c, c1, c2) in current_path.iter().rev() {
+        for &(r, c, c1, c2, score) in current_path.iter().rev() {
             aligned1.push(c1);
             aligned2.push(c2);
             path.push(PathStep { i: r, j: c });
+            columns.push((c1, c2, score));
         }
 
+        let (column_scores, match_line) = build_conservation_track(&columns);
+
         results.push(Alignment {
             aligned_seq1: aligned1,
             aligned_seq2: aligned2,
             path,
+            start_i: 0,
+            start_j: 0,
+            end_i,
+            end_j,
+            column_scores,
+            match_line,
+            rendered: None,
         });
         return;
     }
@@ -150,26 +216,678 @@ fn find_all_paths_linear(
 
     // Diagonal (match/mismatch)
     if i > 0 && j > 0 && (dir & 1) != 0 {
-        current_path.push((i, j, seq1[i - 1], seq2[j - 1]));
-        find_all_paths_linear(i - 1, j - 1, directions, seq1, seq2, current_path, results, max_paths);
+        let score = scorer.score(seq1[i - 1], seq2[j - 1]);
+        current_path.push((i, j, seq1[i - 1], seq2[j - 1], score));
+        find_all_paths_linear(i - 1, j - 1, end_i, end_j, directions, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
         current_path.pop();
     }
 
     // Up (gap in seq2)
     if i > 0 && (dir & 2) != 0 && results.len() < max_paths {
-        current_path.push((i, j, seq1[i - 1], '-'));
-        find_all_paths_linear(i - 1, j, directions, seq1, seq2, current_path, results, max_paths);
+        current_path.push((i, j, seq1[i - 1], '-', gap_penalty));
+        find_all_paths_linear(i - 1, j, end_i, end_j, directions, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
         current_path.pop();
     }
 
     // Left (gap in seq1)
     if j > 0 && (dir & 4) != 0 && results.len() < max_paths {
-        current_path.push((i, j, '-', seq2[j - 1]));
-        find_all_paths_linear(i, j - 1, directions, seq1, seq2, current_path, results, max_paths);
+        current_path.push((i, j, '-', seq2[j - 1], gap_penalty));
+        find_all_paths_linear(i, j - 1, end_i, end_j, directions, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
         current_path.pop();
     }
 }
 
+/// Needleman-Wunsch algorithm with affine (Gotoh) gap penalties.
+///
+/// Maintains three matrices: `m` (best score with `seq1[i]`/`seq2[j]` aligned
+/// to each other), `x` (best score ending in a gap in `seq2`), and `y` (best
+/// score ending in a gap in `seq1`). A run of `k` gaps costs
+/// `gap_open + k * gap_extend` instead of the `k * gap_penalty` used by
+/// [`needleman_wunsch_linear`].
+pub fn needleman_wunsch_affine(
+    seq1: &str,
+    seq2: &str,
+    scorer: &dyn Scorer,
+    gap_open: f32,
+    gap_extend: f32,
+    max_paths: usize,
+) -> AlignmentOutput {
+    let seq1: Vec<char> = seq1.chars().collect();
+    let seq2: Vec<char> = seq2.chars().collect();
+    let n = seq1.len();
+    let m = seq2.len();
+
+    const NEG_INF: f32 = f32::NEG_INFINITY;
+
+    // M: both sequences aligned (match/mismatch). First row/column seeded
+    // with -inf so a path cannot start mid-match.
+    let mut mat_m = vec![vec![NEG_INF; m + 1]; n + 1];
+    // X: best score ending in a gap in seq2 (seq1 character consumed).
+    let mut mat_x = vec![vec![NEG_INF; m + 1]; n + 1];
+    // Y: best score ending in a gap in seq1 (seq2 character consumed).
+    let mut mat_y = vec![vec![NEG_INF; m + 1]; n + 1];
+
+    // Direction matrices: bitmask of which matrix fed the best transition.
+    // dir_m: 1=from M, 2=from X, 4=from Y (diagonal move).
+    // dir_x: 1=gap-open from M, 2=gap-extend from X (up move).
+    // dir_y: 1=gap-open from M, 2=gap-extend from Y (left move).
+    let mut dir_m = vec![vec![0u8; m + 1]; n + 1];
+    let mut dir_x = vec![vec![0u8; m + 1]; n + 1];
+    let mut dir_y = vec![vec![0u8; m + 1]; n + 1];
+
+    mat_m[0][0] = 0.0;
+
+    for i in 1..=n {
+        mat_x[i][0] = gap_open + (i as f32) * gap_extend;
+        // The first step opens the gap (mat_x[0][0] is never computed, so the
+        // only valid predecessor is mat_m[0][0]); later steps extend it.
+        dir_x[i][0] = if i == 1 { 1 } else { 2 };
+    }
+    for j in 1..=m {
+        mat_y[0][j] = gap_open + (j as f32) * gap_extend;
+        // Same reasoning as dir_x above: the first step opens, not extends.
+        dir_y[0][j] = if j == 1 { 1 } else { 2 };
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let char_score = scorer.score(seq1[i - 1], seq2[j - 1]);
+
+            let diag_candidates = &[
+                (1, mat_m[i - 1][j - 1] + char_score, mat_m[i - 1][j - 1]),
+                (2, mat_x[i - 1][j - 1] + char_score, mat_x[i - 1][j - 1]),
+                (4, mat_y[i - 1][j - 1] + char_score, mat_y[i - 1][j - 1]),
+            ];
+            mat_m[i][j] = diag_candidates
+                .iter()
+                .map(|(_, val, _)| *val)
+                .fold(NEG_INF, f32::max);
+            dir_m[i][j] = record_all_optimal_directions(diag_candidates);
+
+            let up_candidates = &[
+                (1, mat_m[i - 1][j] + gap_open + gap_extend, mat_m[i - 1][j]),
+                (2, mat_x[i - 1][j] + gap_extend, mat_x[i - 1][j]),
+            ];
+            mat_x[i][j] = up_candidates
+                .iter()
+                .map(|(_, val, _)| *val)
+                .fold(NEG_INF, f32::max);
+            dir_x[i][j] = record_all_optimal_directions(up_candidates);
+
+            let left_candidates = &[
+                (1, mat_m[i][j - 1] + gap_open + gap_extend, mat_m[i][j - 1]),
+                (2, mat_y[i][j - 1] + gap_extend, mat_y[i][j - 1]),
+            ];
+            mat_y[i][j] = left_candidates
+                .iter()
+                .map(|(_, val, _)| *val)
+                .fold(NEG_INF, f32::max);
+            dir_y[i][j] = record_all_optimal_directions(left_candidates);
+        }
+    }
+
+    let score = mat_m[n][m].max(mat_x[n][m]).max(mat_y[n][m]);
+
+    // =========================================================================
+    // Traceback to find all optimal alignments
+    // =========================================================================
+
+    let mut alignments = Vec::new();
+    for state in [AffineState::M, AffineState::X, AffineState::Y] {
+        if alignments.len() >= max_paths {
+            break;
+        }
+        let final_score = match state {
+            AffineState::M => mat_m[n][m],
+            AffineState::X => mat_x[n][m],
+            AffineState::Y => mat_y[n][m],
+        };
+        if final_score != score {
+            continue;
+        }
+        find_all_paths_affine(
+            n,
+            m,
+            n,
+            m,
+            state,
+            &dir_m,
+            &dir_x,
+            &dir_y,
+            &seq1,
+            &seq2,
+            scorer,
+            gap_open,
+            gap_extend,
+            &mut Vec::new(),
+            &mut alignments,
+            max_paths,
+        );
+    }
+
+    // Combined matrix for visualization: the best of the three states at
+    // each cell.
+    let mut combined = vec![vec![NEG_INF; m + 1]; n + 1];
+    for i in 0..=n {
+        for j in 0..=m {
+            combined[i][j] = mat_m[i][j].max(mat_x[i][j]).max(mat_y[i][j]);
+        }
+    }
+
+    AlignmentOutput {
+        score,
+        alignments,
+        matrices: Some(Matrices { m: combined }),
+    }
+}
+
+/// Which of the three Gotoh matrices a traceback step is currently in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AffineState {
+    M,
+    X,
+    Y,
+}
+
+/// Recursive traceback for affine gap penalty mode.
+#[allow(clippy::too_many_arguments)]
+fn find_all_paths_affine(
+    i: usize,
+    j: usize,
+    end_i: usize,
+    end_j: usize,
+    state: AffineState,
+    dir_m: &[Vec<u8>],
+    dir_x: &[Vec<u8>],
+    dir_y: &[Vec<u8>],
+    seq1: &[char],
+    seq2: &[char],
+    scorer: &dyn Scorer,
+    gap_open: f32,
+    gap_extend: f32,
+    current_path: &mut Vec<(usize, usize, char, char, f32)>,
+    results: &mut Vec<Alignment>,
+    max_paths: usize,
+) {
+    if results.len() >= max_paths {
+        return;
+    }
+
+    if i == 0 && j == 0 {
+        let mut aligned1 = String::new();
+        let mut aligned2 = String::new();
+        let mut path = vec![PathStep { i: 0, j: 0 }];
+        let mut columns = Vec::with_capacity(current_path.len());
+
+        for &(r, c, c1, c2, score) in current_path.iter().rev() {
+            aligned1.push(c1);
+            aligned2.push(c2);
+            path.push(PathStep { i: r, j: c });
+            columns.push((c1, c2, score));
+        }
+
+        let (column_scores, match_line) = build_conservation_track(&columns);
+
+        results.push(Alignment {
+            aligned_seq1: aligned1,
+            aligned_seq2: aligned2,
+            path,
+            start_i: 0,
+            start_j: 0,
+            end_i,
+            end_j,
+            column_scores,
+            match_line,
+            rendered: None,
+        });
+        return;
+    }
+
+    match state {
+        AffineState::M => {
+            if i == 0 || j == 0 {
+                return;
+            }
+            let dir = dir_m[i][j];
+            let score = scorer.score(seq1[i - 1], seq2[j - 1]);
+            current_path.push((i, j, seq1[i - 1], seq2[j - 1], score));
+            if dir & 1 != 0 {
+                find_all_paths_affine(
+                    i - 1, j - 1, end_i, end_j, AffineState::M, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+            }
+            if dir & 2 != 0 && results.len() < max_paths {
+                find_all_paths_affine(
+                    i - 1, j - 1, end_i, end_j, AffineState::X, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+            }
+            if dir & 4 != 0 && results.len() < max_paths {
+                find_all_paths_affine(
+                    i - 1, j - 1, end_i, end_j, AffineState::Y, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+            }
+            current_path.pop();
+        }
+        AffineState::X => {
+            if i == 0 {
+                return;
+            }
+            let dir = dir_x[i][j];
+            // Gap-open is only charged on the step that enters this gap run.
+            if dir & 1 != 0 {
+                current_path.push((i, j, seq1[i - 1], '-', gap_open + gap_extend));
+                find_all_paths_affine(
+                    i - 1, j, end_i, end_j, AffineState::M, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+                current_path.pop();
+            }
+            if dir & 2 != 0 && results.len() < max_paths {
+                current_path.push((i, j, seq1[i - 1], '-', gap_extend));
+                find_all_paths_affine(
+                    i - 1, j, end_i, end_j, AffineState::X, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+                current_path.pop();
+            }
+        }
+        AffineState::Y => {
+            if j == 0 {
+                return;
+            }
+            let dir = dir_y[i][j];
+            if dir & 1 != 0 {
+                current_path.push((i, j, '-', seq2[j - 1], gap_open + gap_extend));
+                find_all_paths_affine(
+                    i, j - 1, end_i, end_j, AffineState::M, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+                current_path.pop();
+            }
+            if dir & 2 != 0 && results.len() < max_paths {
+                current_path.push((i, j, '-', seq2[j - 1], gap_extend));
+                find_all_paths_affine(
+                    i, j - 1, end_i, end_j, AffineState::Y, dir_m, dir_x, dir_y, seq1, seq2,
+                    scorer, gap_open, gap_extend, current_path, results, max_paths,
+                );
+                current_path.pop();
+            }
+        }
+    }
+}
+
+/// Smith-Waterman local alignment: finds the best-scoring subregion instead
+/// of forcing an end-to-end alignment. Identical matrix fill to
+/// [`needleman_wunsch_linear`] except every cell is clamped at zero, and
+/// traceback starts from the global maximum cell(s) and stops as soon as it
+/// reaches a zero cell.
+///
+/// If no subregion scores above zero (the sequences share no similarity),
+/// `score` is `0.0` and `alignments` is empty rather than containing a
+/// trivial zero-length alignment — callers should check for an empty
+/// `alignments` list before indexing into it.
+pub fn smith_waterman_local(
+    seq1: &str,
+    seq2: &str,
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+    max_paths: usize,
+) -> AlignmentOutput {
+    let seq1: Vec<char> = seq1.chars().collect();
+    let seq2: Vec<char> = seq2.chars().collect();
+    let n = seq1.len();
+    let m = seq2.len();
+
+    // First row/column stay at zero: a local alignment can start anywhere.
+    let mut matrix = vec![vec![0.0f32; m + 1]; n + 1];
+    let mut directions = vec![vec![0u8; m + 1]; n + 1];
+
+    let mut global_max = 0.0f32;
+    let mut max_cells: Vec<(usize, usize)> = Vec::new();
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let char_score = scorer.score(seq1[i - 1], seq2[j - 1]);
+
+            let diag = matrix[i - 1][j - 1] + char_score;
+            let up = matrix[i - 1][j] + gap_penalty;
+            let left = matrix[i][j - 1] + gap_penalty;
+
+            let max_val = diag.max(up).max(left).max(0.0);
+            matrix[i][j] = max_val;
+
+            if max_val > 0.0 {
+                let candidates = &[
+                    (1, diag, matrix[i - 1][j - 1]),
+                    (2, up, matrix[i - 1][j]),
+                    (4, left, matrix[i][j - 1]),
+                ];
+                directions[i][j] = record_optimal_directions(candidates);
+            }
+
+            if max_val > global_max {
+                global_max = max_val;
+                max_cells.clear();
+                max_cells.push((i, j));
+            } else if max_val == global_max && max_val > 0.0 {
+                max_cells.push((i, j));
+            }
+        }
+    }
+
+    let mut alignments = Vec::new();
+    for (i, j) in max_cells {
+        if alignments.len() >= max_paths {
+            break;
+        }
+        find_all_paths_local(
+            i,
+            j,
+            i,
+            j,
+            &directions,
+            &matrix,
+            &seq1,
+            &seq2,
+            scorer,
+            gap_penalty,
+            &mut Vec::new(),
+            &mut alignments,
+            max_paths,
+        );
+    }
+
+    AlignmentOutput {
+        score: global_max,
+        alignments,
+        matrices: Some(Matrices { m: matrix }),
+    }
+}
+
+/// Recursive traceback for local alignment mode: stops as soon as it reaches
+/// a zero-valued cell rather than walking all the way back to (0, 0).
+#[allow(clippy::too_many_arguments)]
+fn find_all_paths_local(
+    i: usize,
+    j: usize,
+    end_i: usize,
+    end_j: usize,
+    directions: &[Vec<u8>],
+    matrix: &[Vec<f32>],
+    seq1: &[char],
+    seq2: &[char],
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+    current_path: &mut Vec<(usize, usize, char, char, f32)>,
+    results: &mut Vec<Alignment>,
+    max_paths: usize,
+) {
+    if results.len() >= max_paths {
+        return;
+    }
+
+    if matrix[i][j] == 0.0 {
+        let mut aligned1 = String::new();
+        let mut aligned2 = String::new();
+        let mut path = vec![PathStep { i, j }];
+        let mut columns = Vec::with_capacity(current_path.len());
+
+        for &(r, c, c1, c2, score) in current_path.iter().rev() {
+            aligned1.push(c1);
+            aligned2.push(c2);
+            path.push(PathStep { i: r, j: c });
+            columns.push((c1, c2, score));
+        }
+
+        let (column_scores, match_line) = build_conservation_track(&columns);
+
+        results.push(Alignment {
+            aligned_seq1: aligned1,
+            aligned_seq2: aligned2,
+            path,
+            start_i: i,
+            start_j: j,
+            end_i,
+            end_j,
+            column_scores,
+            match_line,
+            rendered: None,
+        });
+        return;
+    }
+
+    let dir = directions[i][j];
+
+    if i > 0 && j > 0 && (dir & 1) != 0 {
+        let score = scorer.score(seq1[i - 1], seq2[j - 1]);
+        current_path.push((i, j, seq1[i - 1], seq2[j - 1], score));
+        find_all_paths_local(i - 1, j - 1, end_i, end_j, directions, matrix, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
+        current_path.pop();
+    }
+
+    if i > 0 && (dir & 2) != 0 && results.len() < max_paths {
+        current_path.push((i, j, seq1[i - 1], '-', gap_penalty));
+        find_all_paths_local(i - 1, j, end_i, end_j, directions, matrix, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
+        current_path.pop();
+    }
+
+    if j > 0 && (dir & 4) != 0 && results.len() < max_paths {
+        current_path.push((i, j, '-', seq2[j - 1], gap_penalty));
+        find_all_paths_local(i, j - 1, end_i, end_j, directions, matrix, seq1, seq2, scorer, gap_penalty, current_path, results, max_paths);
+        current_path.pop();
+    }
+}
+
+/// Needleman-Wunsch alignment in linear space via Hirschberg's
+/// divide-and-conquer algorithm. Returns the score and a single optimal
+/// alignment without materializing the full O(n·m) score matrix: time
+/// remains O(n·m), same as the full-matrix approach, but space drops from
+/// O(n·m) to O(n + m).
+pub fn needleman_wunsch_hirschberg(
+    seq1: &str,
+    seq2: &str,
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+) -> AlignmentOutput {
+    let seq1: Vec<char> = seq1.chars().collect();
+    let seq2: Vec<char> = seq2.chars().collect();
+    let n = seq1.len();
+    let m = seq2.len();
+
+    let (aligned1, aligned2) = hirschberg_align(&seq1, &seq2, scorer, gap_penalty);
+
+    let mut path = vec![PathStep { i: 0, j: 0 }];
+    let mut columns = Vec::with_capacity(aligned1.len());
+    let (mut i, mut j) = (0usize, 0usize);
+    for (&c1, &c2) in aligned1.iter().zip(aligned2.iter()) {
+        if c1 != '-' {
+            i += 1;
+        }
+        if c2 != '-' {
+            j += 1;
+        }
+        path.push(PathStep { i, j });
+        let score = if c1 == '-' || c2 == '-' {
+            gap_penalty
+        } else {
+            scorer.score(c1, c2)
+        };
+        columns.push((c1, c2, score));
+    }
+
+    let (column_scores, match_line) = build_conservation_track(&columns);
+    let score = column_scores.iter().sum();
+
+    AlignmentOutput {
+        score,
+        alignments: vec![Alignment {
+            aligned_seq1: aligned1.into_iter().collect(),
+            aligned_seq2: aligned2.into_iter().collect(),
+            path,
+            start_i: 0,
+            start_j: 0,
+            end_i: n,
+            end_j: m,
+            column_scores,
+            match_line,
+            rendered: None,
+        }],
+        matrices: None,
+    }
+}
+
+/// Computes the last row of the Needleman-Wunsch score matrix for `seq1`
+/// aligned against `seq2`, using a single rolling O(`seq2.len()`) buffer.
+fn nw_score_row(seq1: &[char], seq2: &[char], scorer: &dyn Scorer, gap_penalty: f32) -> Vec<f32> {
+    let m = seq2.len();
+    let mut prev = vec![0.0f32; m + 1];
+    for (j, cell) in prev.iter_mut().enumerate() {
+        *cell = (j as f32) * gap_penalty;
+    }
+
+    for i in 1..=seq1.len() {
+        let mut curr = vec![0.0f32; m + 1];
+        curr[0] = (i as f32) * gap_penalty;
+        for j in 1..=m {
+            let diag = prev[j - 1] + scorer.score(seq1[i - 1], seq2[j - 1]);
+            let up = prev[j] + gap_penalty;
+            let left = curr[j - 1] + gap_penalty;
+            curr[j] = diag.max(up).max(left);
+        }
+        prev = curr;
+    }
+
+    prev
+}
+
+/// Recursively aligns `seq1` and `seq2` in linear space, returning the two
+/// aligned sequences (with `-` marking gaps) without ever allocating an
+/// O(n·m) matrix.
+fn hirschberg_align(
+    seq1: &[char],
+    seq2: &[char],
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+) -> (Vec<char>, Vec<char>) {
+    let n = seq1.len();
+    let m = seq2.len();
+
+    if n == 0 {
+        return (vec!['-'; m], seq2.to_vec());
+    }
+    if m == 0 {
+        return (seq1.to_vec(), vec!['-'; n]);
+    }
+    if n == 1 || m == 1 {
+        return align_small(seq1, seq2, scorer, gap_penalty);
+    }
+
+    let mid = n / 2;
+
+    let score_l = nw_score_row(&seq1[..mid], seq2, scorer, gap_penalty);
+
+    let seq1_right_rev: Vec<char> = seq1[mid..].iter().rev().cloned().collect();
+    let seq2_rev: Vec<char> = seq2.iter().rev().cloned().collect();
+    let score_r_rev = nw_score_row(&seq1_right_rev, &seq2_rev, scorer, gap_penalty);
+
+    // Find the column k that maximizes the sum of the forward pass over
+    // seq1[..mid] and the backward pass over seq1[mid..].
+    let mut best_k = 0;
+    let mut best_val = f32::NEG_INFINITY;
+    for k in 0..=m {
+        let val = score_l[k] + score_r_rev[m - k];
+        if val > best_val {
+            best_val = val;
+            best_k = k;
+        }
+    }
+
+    let (mut aligned1, mut aligned2) =
+        hirschberg_align(&seq1[..mid], &seq2[..best_k], scorer, gap_penalty);
+    let (right1, right2) = hirschberg_align(&seq1[mid..], &seq2[best_k..], scorer, gap_penalty);
+    aligned1.extend(right1);
+    aligned2.extend(right2);
+
+    (aligned1, aligned2)
+}
+
+/// Direct (non-divide-and-conquer) alignment for Hirschberg's base case,
+/// where one of the two sequences has length 0 or 1. The score matrix here
+/// has a short dimension, so the full matrix is already linear in size.
+fn align_small(
+    seq1: &[char],
+    seq2: &[char],
+    scorer: &dyn Scorer,
+    gap_penalty: f32,
+) -> (Vec<char>, Vec<char>) {
+    let n = seq1.len();
+    let m = seq2.len();
+
+    let mut matrix = vec![vec![0.0f32; m + 1]; n + 1];
+    let mut directions = vec![vec![0u8; m + 1]; n + 1];
+
+    for i in 1..=n {
+        matrix[i][0] = (i as f32) * gap_penalty;
+        directions[i][0] = 2;
+    }
+    for j in 1..=m {
+        matrix[0][j] = (j as f32) * gap_penalty;
+        directions[0][j] = 4;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let diag = matrix[i - 1][j - 1] + scorer.score(seq1[i - 1], seq2[j - 1]);
+            let up = matrix[i - 1][j] + gap_penalty;
+            let left = matrix[i][j - 1] + gap_penalty;
+            let max_val = diag.max(up).max(left);
+            matrix[i][j] = max_val;
+            directions[i][j] = if max_val == diag {
+                1
+            } else if max_val == up {
+                2
+            } else {
+                4
+            };
+        }
+    }
+
+    let mut aligned1 = Vec::with_capacity(n + m);
+    let mut aligned2 = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        let dir = if i == 0 {
+            4
+        } else if j == 0 {
+            2
+        } else {
+            directions[i][j]
+        };
+
+        if dir & 1 != 0 {
+            aligned1.push(seq1[i - 1]);
+            aligned2.push(seq2[j - 1]);
+            i -= 1;
+            j -= 1;
+        } else if dir & 2 != 0 {
+            aligned1.push(seq1[i - 1]);
+            aligned2.push('-');
+            i -= 1;
+        } else {
+            aligned1.push('-');
+            aligned2.push(seq2[j - 1]);
+            j -= 1;
+        }
+    }
+
+    aligned1.reverse();
+    aligned2.reverse();
+    (aligned1, aligned2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +931,139 @@ mod tests {
         let result = needleman_wunsch_linear("ACGT", "ACGT", &scorer, -2.0, 10);
         assert_eq!(result.score, 20.0); // 4 matches × 5
     }
+
+    #[test]
+    fn test_linear_conservation_track() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = needleman_wunsch_linear("ACGT", "ACT", &scorer, -2.0, 10);
+        let alignment = &result.alignments[0];
+        assert_eq!(alignment.column_scores.len(), alignment.aligned_seq1.len());
+        assert_eq!(alignment.match_line.len(), alignment.aligned_seq1.len());
+        // A-C-G-T vs A-C---T: three matches marked '|' and one gap marked ' '
+        assert_eq!(alignment.match_line, "|| |");
+        assert_eq!(alignment.column_scores, vec![5.0, 5.0, -2.0, 5.0]);
+    }
+
+    #[test]
+    fn test_affine_identical() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = needleman_wunsch_affine("ACGT", "ACGT", &scorer, -10.0, -1.0, 10);
+        assert_eq!(result.score, 20.0); // 4 matches × 5, no gaps
+        assert_eq!(result.alignments[0].aligned_seq1, "ACGT");
+        assert_eq!(result.alignments[0].aligned_seq2, "ACGT");
+        assert_eq!(result.alignments[0].match_line, "||||");
+    }
+
+    #[test]
+    fn test_affine_prefers_single_gap_run() {
+        let scorer = simple_scorer(5.0, -4.0);
+        // AC--T vs ACGGT: one gap-open + one extra extend beats two
+        // isolated single-character gaps under an affine penalty.
+        let result = needleman_wunsch_affine("ACT", "ACGGT", &scorer, -10.0, -1.0, 10);
+        // 3 matches (15.0) + one gap run of length 2 (open -10 + 2 × extend -1 = -12) = 3.0
+        assert_eq!(result.score, 3.0);
+    }
+
+    #[test]
+    fn test_affine_matches_linear_for_single_gaps() {
+        // With gap_open = 0.0, affine collapses to a linear gap penalty.
+        let scorer = simple_scorer(5.0, -4.0);
+        let affine = needleman_wunsch_affine("ACGT", "ACT", &scorer, 0.0, -2.0, 10);
+        let linear = needleman_wunsch_linear("ACGT", "ACT", &scorer, -2.0, 10);
+        assert_eq!(affine.score, linear.score);
+    }
+
+    #[test]
+    fn test_affine_boundary_gap_column_scores_sum_to_score() {
+        // The optimal alignment opens a gap immediately at the start:
+        // ACGT vs --GT. The first step of that boundary gap run must be
+        // charged gap_open + gap_extend, not gap_extend alone, or
+        // column_scores silently under-costs it relative to `score`.
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = needleman_wunsch_affine("ACGT", "GT", &scorer, -10.0, -1.0, 10);
+        assert_eq!(result.score, -2.0); // 2 matches (10.0) + one gap run of length 2 (-12.0)
+        let alignment = &result.alignments[0];
+        assert_eq!(alignment.aligned_seq1, "ACGT");
+        assert_eq!(alignment.aligned_seq2, "--GT");
+        assert_eq!(alignment.column_scores, vec![-11.0, -1.0, 5.0, 5.0]);
+        assert_eq!(alignment.column_scores.iter().sum::<f32>(), result.score);
+    }
+
+    #[test]
+    fn test_affine_enumerates_all_co_optimal_alignments() {
+        // "AA" vs "ABB" has three score-(-2) alignments under gap_open=-2,
+        // gap_extend=-1: two single-gap alignments plus a third where a
+        // leading gap-open in seq2 and a trailing two-gap run in seq1 tie
+        // the same total. The third one is only reachable by a transition
+        // whose predecessor score is lower than a sibling transition that
+        // reaches the same cell value, which `record_optimal_directions`'s
+        // extra "max predecessor" filter would incorrectly discard.
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = needleman_wunsch_affine("AA", "ABB", &scorer, -2.0, -1.0, 10);
+        assert_eq!(result.score, -2.0);
+
+        let mut pairs: Vec<(&str, &str)> = result
+            .alignments
+            .iter()
+            .map(|a| (a.aligned_seq1.as_str(), a.aligned_seq2.as_str()))
+            .collect();
+        pairs.sort();
+        assert_eq!(
+            pairs,
+            vec![("A-A", "ABB"), ("AA-", "ABB"), ("AA--", "-ABB")]
+        );
+    }
+
+    #[test]
+    fn test_local_finds_conserved_motif() {
+        let scorer = simple_scorer(5.0, -4.0);
+        // "ACGT" is embedded inside both longer, otherwise unrelated sequences.
+        let result = smith_waterman_local("TTTTACGTGGGG", "CCCCACGTAAAA", &scorer, -2.0, 10);
+        assert_eq!(result.score, 20.0); // 4 matches × 5
+        assert_eq!(result.alignments[0].aligned_seq1, "ACGT");
+        assert_eq!(result.alignments[0].aligned_seq2, "ACGT");
+        assert_eq!(result.alignments[0].start_i, 4);
+        assert_eq!(result.alignments[0].start_j, 4);
+        assert_eq!(result.alignments[0].end_i, 8);
+        assert_eq!(result.alignments[0].end_j, 8);
+        assert_eq!(result.alignments[0].match_line, "||||");
+    }
+
+    #[test]
+    fn test_local_no_similarity_scores_zero() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = smith_waterman_local("AAAA", "TTTT", &scorer, -2.0, 10);
+        assert_eq!(result.score, 0.0);
+        // No subregion scores above zero, so no alignment is reported at
+        // all (not a trivial zero-length one) — see the doc comment on
+        // `smith_waterman_local`.
+        assert!(result.alignments.is_empty());
+    }
+
+    #[test]
+    fn test_hirschberg_matches_full_matrix_score() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let full = needleman_wunsch_linear("ACGT", "ACT", &scorer, -2.0, 10);
+        let linear_space = needleman_wunsch_hirschberg("ACGT", "ACT", &scorer, -2.0);
+        assert_eq!(linear_space.score, full.score);
+        assert!(linear_space.matrices.is_none());
+        assert_eq!(linear_space.alignments.len(), 1);
+    }
+
+    #[test]
+    fn test_hirschberg_identical() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let result = needleman_wunsch_hirschberg("ACGT", "ACGT", &scorer, -2.0);
+        assert_eq!(result.score, 20.0);
+        assert_eq!(result.alignments[0].aligned_seq1, "ACGT");
+        assert_eq!(result.alignments[0].aligned_seq2, "ACGT");
+    }
+
+    #[test]
+    fn test_hirschberg_matches_full_matrix_on_longer_input() {
+        let scorer = simple_scorer(5.0, -4.0);
+        let full = needleman_wunsch_linear("GATTACA", "GCATGCU", &scorer, -2.0, 10);
+        let linear_space = needleman_wunsch_hirschberg("GATTACA", "GCATGCU", &scorer, -2.0);
+        assert_eq!(linear_space.score, full.score);
+    }
 }
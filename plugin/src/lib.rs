@@ -3,11 +3,19 @@ use wasm_minimal_protocol::*;
 
 pub mod algorithm;
 pub mod matrices;
+pub mod render;
 pub mod scoring;
 
-pub use matrices::{available_matrices, get_ednafull_matrix, get_matrix_by_name};
+pub use matrices::{
+    available_matrices, get_blosum62_matrix, get_ednafull_matrix, get_matrix_by_name,
+    get_pam250_matrix,
+};
+pub use render::RenderedAlignment;
 use scoring::{Scorer, SimpleScorer, MatrixScorer};
-use algorithm::needleman_wunsch_linear;
+use algorithm::{
+    needleman_wunsch_affine, needleman_wunsch_hirschberg, needleman_wunsch_linear,
+    smith_waterman_local,
+};
 
 initiate_protocol!();
 
@@ -35,6 +43,18 @@ pub enum ScoringConfig {
     Matrix { matrix: String },
 }
 
+/// Gap scoring configuration - either a single linear cost per gap character,
+/// or separate gap-open/gap-extend costs (Gotoh affine gaps).
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum GapPenalty {
+    /// Linear gap penalty (cost per gap character)
+    Linear { gap_penalty: f32 },
+    /// Affine gap penalty: `gap_open` is paid once per gap run, `gap_extend`
+    /// once per gap character in that run
+    Affine { gap_open: f32, gap_extend: f32 },
+}
+
 /// Input parameters for sequence alignment.
 #[derive(Deserialize)]
 pub struct AlignmentInput {
@@ -42,10 +62,35 @@ pub struct AlignmentInput {
     pub seq2: String,
     /// Scoring configuration: either { "match": 5, "mismatch": -4 } or { "matrix": "EDNAFULL" }
     pub scores: ScoringConfig,
-    /// Linear gap penalty (cost per gap character)
-    pub gap_penalty: f32,
+    /// Gap scoring configuration: either { "gap_penalty": -2 } or
+    /// { "gap_open": -10, "gap_extend": -1 }
+    #[serde(flatten)]
+    pub gap: GapPenalty,
+    /// Alignment mode: "global" (Needleman-Wunsch, default) or "local" (Smith-Waterman)
+    #[serde(default)]
+    pub mode: AlignmentMode,
     /// Maximum number of optimal alignments to return
     pub max_paths: Option<usize>,
+    /// Use Hirschberg's linear-space algorithm: returns the score and a
+    /// single optimal alignment without materializing the full O(n·m)
+    /// matrix. Only supported for global alignment with a linear gap penalty.
+    #[serde(default)]
+    pub low_memory: bool,
+    /// Pre-render a block-shaded visualization of each alignment, so a Typst
+    /// template can embed it directly without recomputing scores
+    #[serde(default)]
+    pub render: bool,
+}
+
+/// Alignment mode: forced end-to-end (global) or best-scoring subregion (local).
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AlignmentMode {
+    /// Needleman-Wunsch: align the full length of both sequences
+    #[default]
+    Global,
+    /// Smith-Waterman: find the best-scoring local subregion
+    Local,
 }
 
 // =============================================================================
@@ -66,8 +111,26 @@ pub struct PathStep {
 pub struct Alignment {
     pub aligned_seq1: String,
     pub aligned_seq2: String,
-    /// Ordered traceback path from (0,0) to (n,m)
+    /// Ordered traceback path from (start_i, start_j) to (end_i, end_j)
     pub path: Vec<PathStep>,
+    /// Matrix row where the aligned region begins (0 for global alignments)
+    pub start_i: usize,
+    /// Matrix column where the aligned region begins (0 for global alignments)
+    pub start_j: usize,
+    /// Matrix row where the aligned region ends (n for global alignments)
+    pub end_i: usize,
+    /// Matrix column where the aligned region ends (m for global alignments)
+    pub end_j: usize,
+    /// Per-column substitution score (one entry per aligned position,
+    /// including gap columns) for rendering a conservation track
+    pub column_scores: Vec<f32>,
+    /// Compact BLAST-style midline: `|` for identical columns, `:`/`.` for
+    /// strongly/weakly positive-scoring mismatches, space for gaps or
+    /// non-positive-scoring mismatches
+    pub match_line: String,
+    /// Pre-rendered block-shaded visualization, present when `render` was
+    /// set on the input
+    pub rendered: Option<RenderedAlignment>,
 }
 
 /// Scoring matrices from the alignment algorithm.
@@ -82,10 +145,13 @@ pub struct Matrices {
 pub struct AlignmentOutput {
     /// The optimal alignment score
     pub score: f32,
-    /// List of optimal alignments found
+    /// List of optimal alignments found. Empty with `score == 0.0` means
+    /// local alignment mode found no subregion with positive similarity,
+    /// not that a zero-length alignment was omitted
     pub alignments: Vec<Alignment>,
-    /// The scoring matrices
-    pub matrices: Matrices,
+    /// The scoring matrices, omitted when `low_memory` linear-space alignment
+    /// was used (Hirschberg's algorithm never materializes the full matrix)
+    pub matrices: Option<Matrices>,
 }
 
 // =============================================================================
@@ -121,13 +187,54 @@ pub fn run_alignment(input: AlignmentInput) -> AlignmentOutput {
         }
     };
 
-    needleman_wunsch_linear(
-        &input.seq1,
-        &input.seq2,
-        scorer.as_ref(),
-        input.gap_penalty,
-        input.max_paths.unwrap_or(100),
-    )
+    let max_paths = input.max_paths.unwrap_or(100);
+    let render = input.render;
+
+    let mut output = match (input.low_memory, input.mode, input.gap) {
+        (true, AlignmentMode::Global, GapPenalty::Linear { gap_penalty }) => {
+            needleman_wunsch_hirschberg(&input.seq1, &input.seq2, scorer.as_ref(), gap_penalty)
+        }
+        (true, _, _) => {
+            panic!("low_memory mode is only supported for global alignment with a linear gap penalty")
+        }
+        (false, AlignmentMode::Global, GapPenalty::Linear { gap_penalty }) => {
+            needleman_wunsch_linear(
+                &input.seq1,
+                &input.seq2,
+                scorer.as_ref(),
+                gap_penalty,
+                max_paths,
+            )
+        }
+        (false, AlignmentMode::Global, GapPenalty::Affine { gap_open, gap_extend }) => {
+            needleman_wunsch_affine(
+                &input.seq1,
+                &input.seq2,
+                scorer.as_ref(),
+                gap_open,
+                gap_extend,
+                max_paths,
+            )
+        }
+        (false, AlignmentMode::Local, GapPenalty::Linear { gap_penalty }) => smith_waterman_local(
+            &input.seq1,
+            &input.seq2,
+            scorer.as_ref(),
+            gap_penalty,
+            max_paths,
+        ),
+        (false, AlignmentMode::Local, GapPenalty::Affine { .. }) => {
+            panic!("Local alignment mode does not yet support affine gap penalties")
+        }
+    };
+
+    if render {
+        for alignment in &mut output.alignments {
+            alignment.rendered = Some(render::render_alignment(alignment));
+        }
+    }
+
+    output
 }
 
 #[cfg(test)]
@@ -143,8 +250,11 @@ mod tests {
                 match_score: 5.0,
                 mismatch: -4.0,
             }),
-            gap_penalty: -2.0,
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Global,
             max_paths: Some(1),
+            low_memory: false,
+            render: false,
         };
 
         let result = run_alignment(input);
@@ -159,11 +269,172 @@ mod tests {
             scores: ScoringConfig::Matrix {
                 matrix: "EDNAFULL".to_string(),
             },
-            gap_penalty: -2.0,
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Global,
             max_paths: Some(1),
+            low_memory: false,
+            render: false,
         };
 
         let result = run_alignment(input);
         assert_eq!(result.score, 20.0); // 4 matches Ã— 5 (EDNAFULL)
     }
+
+    #[test]
+    fn test_gap_penalty_affine() {
+        let input = AlignmentInput {
+            seq1: "ACGT".to_string(),
+            seq2: "ACGT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Affine {
+                gap_open: -10.0,
+                gap_extend: -1.0,
+            },
+            mode: AlignmentMode::Global,
+            max_paths: Some(1),
+            low_memory: false,
+            render: false,
+        };
+
+        let result = run_alignment(input);
+        assert_eq!(result.score, 20.0);
+    }
+
+    #[test]
+    fn test_low_memory_hirschberg_matches_full_matrix() {
+        let input = AlignmentInput {
+            seq1: "ACGTACGT".to_string(),
+            seq2: "ACGTCCGT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Global,
+            max_paths: Some(1),
+            low_memory: true,
+            render: false,
+        };
+
+        let result = run_alignment(input);
+        // Several alignments tie for this score (one with a single mismatch,
+        // others with one gap in each sequence), and Hirschberg isn't
+        // guaranteed to pick the same one
+        // as the full-matrix traceback, so check validity rather than one
+        // specific tied string.
+        assert_eq!(result.score, 31.0); // e.g. 7 matches Ã— 5 - 1 mismatch Ã— 4
+        assert!(result.matrices.is_none());
+        assert_eq!(result.alignments.len(), 1);
+        let alignment = &result.alignments[0];
+        assert_eq!(alignment.aligned_seq1.len(), alignment.aligned_seq2.len());
+        assert_eq!(alignment.aligned_seq1.replace('-', ""), "ACGTACGT");
+        assert_eq!(alignment.aligned_seq2.replace('-', ""), "ACGTCCGT");
+    }
+
+    #[test]
+    #[should_panic(expected = "low_memory mode is only supported")]
+    fn test_low_memory_rejects_local_mode() {
+        let input = AlignmentInput {
+            seq1: "ACGT".to_string(),
+            seq2: "ACGT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Local,
+            max_paths: Some(1),
+            low_memory: true,
+            render: false,
+        };
+
+        run_alignment(input);
+    }
+
+    #[test]
+    fn test_render_populates_rendered_field() {
+        let input = AlignmentInput {
+            seq1: "ACGT".to_string(),
+            seq2: "ACT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Global,
+            max_paths: Some(1),
+            low_memory: false,
+            render: true,
+        };
+
+        let result = run_alignment(input);
+        let alignment = &result.alignments[0];
+        let rendered = alignment.rendered.as_ref().expect("rendered output requested");
+        assert_eq!(rendered.seq1_row, alignment.aligned_seq1);
+        assert_eq!(rendered.seq2_row, alignment.aligned_seq2);
+        assert_eq!(
+            rendered.shading_row.chars().count(),
+            alignment.column_scores.len()
+        );
+    }
+
+    #[test]
+    fn test_no_render_leaves_rendered_field_empty() {
+        let input = AlignmentInput {
+            seq1: "ACGT".to_string(),
+            seq2: "ACGT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Linear { gap_penalty: -2.0 },
+            mode: AlignmentMode::Global,
+            max_paths: Some(1),
+            low_memory: false,
+            render: false,
+        };
+
+        let result = run_alignment(input);
+        assert!(result.alignments[0].rendered.is_none());
+    }
+
+    #[test]
+    fn test_render_with_affine_boundary_gap_matches_column_scores() {
+        // Regression test: the shading row must be derived from the
+        // boundary gap run's corrected column_scores (gap_open charged on
+        // its first step), not the under-costed values from before the
+        // chunk0-1 fix.
+        let input = AlignmentInput {
+            seq1: "ACGT".to_string(),
+            seq2: "GT".to_string(),
+            scores: ScoringConfig::Simple(MatchScores {
+                match_score: 5.0,
+                mismatch: -4.0,
+            }),
+            gap: GapPenalty::Affine {
+                gap_open: -10.0,
+                gap_extend: -1.0,
+            },
+            mode: AlignmentMode::Global,
+            max_paths: Some(1),
+            low_memory: false,
+            render: true,
+        };
+
+        let result = run_alignment(input);
+        let alignment = &result.alignments[0];
+        assert_eq!(alignment.column_scores.iter().sum::<f32>(), result.score);
+        let rendered = alignment.rendered.as_ref().expect("rendered output requested");
+        assert_eq!(
+            rendered.shading_row.chars().count(),
+            alignment.column_scores.len()
+        );
+        // The boundary gap-open column is the most negative, so it must get
+        // the deepest penalty glyph in the ramp.
+        let glyphs: Vec<char> = rendered.shading_row.chars().collect();
+        assert_eq!(glyphs[0], '█');
+    }
 }
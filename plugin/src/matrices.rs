@@ -1,6 +1,7 @@
 //! Substitution matrices for sequence alignment.
 //!
-//! This module contains pre-defined substitution matrices for nucleotide alignments.
+//! This module contains pre-defined substitution matrices for nucleotide and
+//! protein alignments.
 
 use std::collections::HashMap;
 
@@ -11,10 +12,11 @@ use std::collections::HashMap;
 /// Lowest score = -4.0, Highest score = 5.0
 pub fn get_ednafull_matrix() -> HashMap<(char, char), f32> {
     let chars = [
-        'A', 'T', 'G', 'C', 'S', 'W', 'R', 'Y', 'K', 'M', 'B', 'V', 'H', 'D', 'N',
+        'A', 'T', 'G', 'C', 'S', 'W', 'R', 'Y', 'K', 'M', 'B', 'V', 'H', 'D', 'N', 'U',
     ];
 
-    // Matrix values row by row (from the EDNAFULL file)
+    // Matrix values row by row (from the EDNAFULL file). U (RNA uracil) is
+    // scored identically to T, since EDNAFULL treats them as equivalent.
     #[rustfmt::skip]
     let values: [[f32; 16]; 16] = [
         [ 5.0, -4.0, -4.0, -4.0, -4.0,  1.0,  1.0, -4.0, -4.0,  1.0, -4.0, -1.0, -1.0, -1.0, -2.0, -4.0], // A
@@ -32,6 +34,7 @@ pub fn get_ednafull_matrix() -> HashMap<(char, char), f32> {
         [-1.0, -1.0, -4.0, -1.0, -3.0, -1.0, -3.0, -1.0, -3.0, -1.0, -2.0, -2.0, -1.0, -2.0, -1.0, -1.0], // H
         [-1.0, -1.0, -1.0, -4.0, -3.0, -1.0, -1.0, -3.0, -1.0, -3.0, -2.0, -2.0, -2.0, -1.0, -1.0, -1.0], // D
         [-2.0, -2.0, -2.0, -2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0], // N
+        [-4.0,  5.0, -4.0, -4.0, -4.0,  1.0, -4.0,  1.0,  1.0, -4.0, -1.0, -4.0, -1.0, -1.0, -2.0,  5.0], // U
     ];
 
     let mut matrix = HashMap::new();
@@ -50,18 +53,123 @@ pub fn get_ednafull_matrix() -> HashMap<(char, char), f32> {
     matrix
 }
 
+/// Get the BLOSUM62 substitution matrix for protein alignments.
+///
+/// Standard 20-amino-acid alphabet plus the ambiguity codes `B` (Asn/Asp),
+/// `Z` (Gln/Glu), `X` (any) and the stop codon `*`.
+pub fn get_blosum62_matrix() -> HashMap<(char, char), f32> {
+    let chars = [
+        'A', 'R', 'N', 'D', 'C', 'Q', 'E', 'G', 'H', 'I', 'L', 'K', 'M', 'F', 'P', 'S', 'T', 'W',
+        'Y', 'V', 'B', 'Z', 'X', '*',
+    ];
+
+    #[rustfmt::skip]
+    let values: [[f32; 24]; 24] = [
+        [ 4.0,-1.0,-2.0,-2.0, 0.0,-1.0,-1.0, 0.0,-2.0,-1.0,-1.0,-1.0,-1.0,-2.0,-1.0, 1.0, 0.0,-3.0,-2.0, 0.0,-2.0,-1.0, 0.0,-4.0], // A
+        [-1.0, 5.0, 0.0,-2.0,-3.0, 1.0, 0.0,-2.0, 0.0,-3.0,-2.0, 2.0,-1.0,-3.0,-2.0,-1.0,-1.0,-3.0,-2.0,-3.0,-1.0, 0.0,-1.0,-4.0], // R
+        [-2.0, 0.0, 6.0, 1.0,-3.0, 0.0, 0.0, 0.0, 1.0,-3.0,-3.0, 0.0,-2.0,-3.0,-2.0, 1.0, 0.0,-4.0,-2.0,-3.0, 3.0, 0.0,-1.0,-4.0], // N
+        [-2.0,-2.0, 1.0, 6.0,-3.0, 0.0, 2.0,-1.0,-1.0,-3.0,-4.0,-1.0,-3.0,-3.0,-1.0, 0.0,-1.0,-4.0,-3.0,-3.0, 4.0, 1.0,-1.0,-4.0], // D
+        [ 0.0,-3.0,-3.0,-3.0, 9.0,-3.0,-4.0,-3.0,-3.0,-1.0,-1.0,-3.0,-1.0,-2.0,-3.0,-1.0,-1.0,-2.0,-2.0,-1.0,-3.0,-3.0,-2.0,-4.0], // C
+        [-1.0, 1.0, 0.0, 0.0,-3.0, 5.0, 2.0,-2.0, 0.0,-3.0,-2.0, 1.0, 0.0,-3.0,-1.0, 0.0,-1.0,-2.0,-1.0,-2.0, 0.0, 3.0,-1.0,-4.0], // Q
+        [-1.0, 0.0, 0.0, 2.0,-4.0, 2.0, 5.0,-2.0, 0.0,-3.0,-3.0, 1.0,-2.0,-3.0,-1.0, 0.0,-1.0,-3.0,-2.0,-2.0, 1.0, 4.0,-1.0,-4.0], // E
+        [ 0.0,-2.0, 0.0,-1.0,-3.0,-2.0,-2.0, 6.0,-2.0,-4.0,-4.0,-2.0,-3.0,-3.0,-2.0, 0.0,-2.0,-2.0,-3.0,-3.0,-1.0,-2.0,-1.0,-4.0], // G
+        [-2.0, 0.0, 1.0,-1.0,-3.0, 0.0, 0.0,-2.0, 8.0,-3.0,-3.0,-1.0,-2.0,-1.0,-2.0,-1.0,-2.0,-2.0, 2.0,-3.0, 0.0, 0.0,-1.0,-4.0], // H
+        [-1.0,-3.0,-3.0,-3.0,-1.0,-3.0,-3.0,-4.0,-3.0, 4.0, 2.0,-3.0, 1.0, 0.0,-3.0,-2.0,-1.0,-3.0,-1.0, 3.0,-3.0,-3.0,-1.0,-4.0], // I
+        [-1.0,-2.0,-3.0,-4.0,-1.0,-2.0,-3.0,-4.0,-3.0, 2.0, 4.0,-2.0, 2.0, 0.0,-3.0,-2.0,-1.0,-2.0,-1.0, 1.0,-4.0,-3.0,-1.0,-4.0], // L
+        [-1.0, 2.0, 0.0,-1.0,-3.0, 1.0, 1.0,-2.0,-1.0,-3.0,-2.0, 5.0,-1.0,-3.0,-1.0, 0.0,-1.0,-3.0,-2.0,-2.0, 0.0, 1.0,-1.0,-4.0], // K
+        [-1.0,-1.0,-2.0,-3.0,-1.0, 0.0,-2.0,-3.0,-2.0, 1.0, 2.0,-1.0, 5.0, 0.0,-2.0,-1.0,-1.0,-1.0,-1.0, 1.0,-3.0,-1.0,-1.0,-4.0], // M
+        [-2.0,-3.0,-3.0,-3.0,-2.0,-3.0,-3.0,-3.0,-1.0, 0.0, 0.0,-3.0, 0.0, 6.0,-4.0,-2.0,-2.0, 1.0, 3.0,-1.0,-3.0,-3.0,-1.0,-4.0], // F
+        [-1.0,-2.0,-2.0,-1.0,-3.0,-1.0,-1.0,-2.0,-2.0,-3.0,-3.0,-1.0,-2.0,-4.0, 7.0,-1.0,-1.0,-4.0,-3.0,-2.0,-2.0,-1.0,-2.0,-4.0], // P
+        [ 1.0,-1.0, 1.0, 0.0,-1.0, 0.0, 0.0, 0.0,-1.0,-2.0,-2.0, 0.0,-1.0,-2.0,-1.0, 4.0, 1.0,-3.0,-2.0,-2.0, 0.0, 0.0, 0.0,-4.0], // S
+        [ 0.0,-1.0, 0.0,-1.0,-1.0,-1.0,-1.0,-2.0,-2.0,-1.0,-1.0,-1.0,-1.0,-2.0,-1.0, 1.0, 5.0,-2.0,-2.0, 0.0,-1.0,-1.0, 0.0,-4.0], // T
+        [-3.0,-3.0,-4.0,-4.0,-2.0,-2.0,-3.0,-2.0,-2.0,-3.0,-2.0,-3.0,-1.0, 1.0,-4.0,-3.0,-2.0,11.0, 2.0,-3.0,-4.0,-3.0,-2.0,-4.0], // W
+        [-2.0,-2.0,-2.0,-3.0,-2.0,-1.0,-2.0,-3.0, 2.0,-1.0,-1.0,-2.0,-1.0, 3.0,-3.0,-2.0,-2.0, 2.0, 7.0,-1.0,-3.0,-2.0,-1.0,-4.0], // Y
+        [ 0.0,-3.0,-3.0,-3.0,-1.0,-2.0,-2.0,-3.0,-3.0, 3.0, 1.0,-2.0, 1.0,-1.0,-2.0,-2.0, 0.0,-3.0,-1.0, 4.0,-3.0,-2.0,-1.0,-4.0], // V
+        [-2.0,-1.0, 3.0, 4.0,-3.0, 0.0, 1.0,-1.0, 0.0,-3.0,-4.0, 0.0,-3.0,-3.0,-2.0, 0.0,-1.0,-4.0,-3.0,-3.0, 4.0, 1.0,-1.0,-4.0], // B
+        [-1.0, 0.0, 0.0, 1.0,-3.0, 3.0, 4.0,-2.0, 0.0,-3.0,-3.0, 1.0,-1.0,-3.0,-1.0, 0.0,-1.0,-3.0,-2.0,-2.0, 1.0, 4.0,-1.0,-4.0], // Z
+        [ 0.0,-1.0,-1.0,-1.0,-2.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-2.0, 0.0, 0.0,-2.0,-1.0,-1.0,-1.0,-1.0,-1.0,-4.0], // X
+        [-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0,-4.0, 1.0], // *
+    ];
+
+    populate_protein_matrix(&chars, &values)
+}
+
+/// Get the PAM250 substitution matrix for protein alignments.
+///
+/// Same 20-amino-acid alphabet (plus `B`, `Z`, `X`, `*`) as [`get_blosum62_matrix`],
+/// but derived from the PAM250 (250 accepted point mutations) evolutionary model.
+pub fn get_pam250_matrix() -> HashMap<(char, char), f32> {
+    let chars = [
+        'A', 'R', 'N', 'D', 'C', 'Q', 'E', 'G', 'H', 'I', 'L', 'K', 'M', 'F', 'P', 'S', 'T', 'W',
+        'Y', 'V', 'B', 'Z', 'X', '*',
+    ];
+
+    #[rustfmt::skip]
+    let values: [[f32; 24]; 24] = [
+        [ 2.0,-2.0, 0.0, 0.0,-2.0, 0.0, 0.0, 1.0,-1.0,-1.0,-2.0,-1.0,-1.0,-3.0, 1.0, 1.0, 1.0,-6.0,-3.0, 0.0, 0.0, 0.0, 0.0,-8.0], // A
+        [-2.0, 6.0, 0.0,-1.0,-4.0, 1.0,-1.0,-3.0, 2.0,-2.0,-3.0, 3.0, 0.0,-4.0, 0.0, 0.0,-1.0, 2.0,-4.0,-2.0,-1.0,-1.0,-1.0,-8.0], // R
+        [ 0.0, 0.0, 2.0, 2.0,-4.0, 1.0, 1.0, 0.0, 2.0,-2.0,-3.0, 1.0,-2.0,-3.0, 0.0, 1.0, 0.0,-4.0,-2.0,-2.0, 2.0, 1.0, 0.0,-8.0], // N
+        [ 0.0,-1.0, 2.0, 4.0,-5.0, 2.0, 3.0, 1.0, 1.0,-2.0,-4.0, 0.0,-3.0,-6.0,-1.0, 0.0, 0.0,-7.0,-4.0,-2.0, 3.0, 3.0,-1.0,-8.0], // D
+        [-2.0,-4.0,-4.0,-5.0,12.0,-5.0,-5.0,-3.0,-3.0,-2.0,-6.0,-5.0,-5.0,-4.0,-3.0, 0.0,-2.0,-8.0, 0.0,-2.0,-4.0,-5.0,-3.0,-8.0], // C
+        [ 0.0, 1.0, 1.0, 2.0,-5.0, 4.0, 2.0,-1.0, 3.0,-2.0,-2.0, 1.0,-1.0,-5.0, 0.0,-1.0,-1.0,-5.0,-4.0,-2.0, 1.0, 3.0,-1.0,-8.0], // Q
+        [ 0.0,-1.0, 1.0, 3.0,-5.0, 2.0, 4.0, 0.0, 1.0,-2.0,-3.0, 0.0,-2.0,-5.0,-1.0, 0.0, 0.0,-7.0,-4.0,-2.0, 3.0, 3.0,-1.0,-8.0], // E
+        [ 1.0,-3.0, 0.0, 1.0,-3.0,-1.0, 0.0, 5.0,-2.0,-3.0,-4.0,-2.0,-3.0,-5.0, 0.0, 1.0, 0.0,-7.0,-5.0,-1.0, 0.0, 0.0,-1.0,-8.0], // G
+        [-1.0, 2.0, 2.0, 1.0,-3.0, 3.0, 1.0,-2.0, 6.0,-2.0,-2.0, 0.0,-2.0,-2.0, 0.0,-1.0,-1.0,-3.0, 0.0,-2.0, 1.0, 2.0,-1.0,-8.0], // H
+        [-1.0,-2.0,-2.0,-2.0,-2.0,-2.0,-2.0,-3.0,-2.0, 5.0, 2.0,-2.0, 2.0, 1.0,-2.0,-1.0, 0.0,-5.0,-1.0, 4.0,-2.0,-2.0,-1.0,-8.0], // I
+        [-2.0,-3.0,-3.0,-4.0,-6.0,-2.0,-3.0,-4.0,-2.0, 2.0, 6.0,-3.0, 4.0, 2.0,-3.0,-3.0,-2.0,-2.0,-1.0, 2.0,-3.0,-3.0,-1.0,-8.0], // L
+        [-1.0, 3.0, 1.0, 0.0,-5.0, 1.0, 0.0,-2.0, 0.0,-2.0,-3.0, 5.0, 0.0,-5.0,-1.0, 0.0, 0.0,-3.0,-4.0,-2.0, 1.0, 0.0,-1.0,-8.0], // K
+        [-1.0, 0.0,-2.0,-3.0,-5.0,-1.0,-2.0,-3.0,-2.0, 2.0, 4.0, 0.0, 6.0, 0.0,-2.0,-2.0,-1.0,-4.0,-2.0, 2.0,-2.0,-2.0,-1.0,-8.0], // M
+        [-3.0,-4.0,-3.0,-6.0,-4.0,-5.0,-5.0,-5.0,-2.0, 1.0, 2.0,-5.0, 0.0, 9.0,-5.0,-3.0,-3.0, 0.0, 7.0,-1.0,-4.0,-5.0,-2.0,-8.0], // F
+        [ 1.0, 0.0, 0.0,-1.0,-3.0, 0.0,-1.0, 0.0, 0.0,-2.0,-3.0,-1.0,-2.0,-5.0, 6.0, 1.0, 0.0,-6.0,-5.0,-1.0,-1.0, 0.0,-1.0,-8.0], // P
+        [ 1.0, 0.0, 1.0, 0.0, 0.0,-1.0, 0.0, 1.0,-1.0,-1.0,-3.0, 0.0,-2.0,-3.0, 1.0, 2.0, 1.0,-2.0,-3.0,-1.0, 0.0, 0.0, 0.0,-8.0], // S
+        [ 1.0,-1.0, 0.0, 0.0,-2.0,-1.0, 0.0, 0.0,-1.0, 0.0,-2.0, 0.0,-1.0,-3.0, 0.0, 1.0, 3.0,-5.0,-3.0, 0.0, 0.0,-1.0, 0.0,-8.0], // T
+        [-6.0, 2.0,-4.0,-7.0,-8.0,-5.0,-7.0,-7.0,-3.0,-5.0,-2.0,-3.0,-4.0, 0.0,-6.0,-2.0,-5.0,17.0, 0.0,-6.0,-5.0,-6.0,-4.0,-8.0], // W
+        [-3.0,-4.0,-2.0,-4.0, 0.0,-4.0,-4.0,-5.0, 0.0,-1.0,-1.0,-4.0,-2.0, 7.0,-5.0,-3.0,-3.0, 0.0,10.0,-2.0,-3.0,-4.0,-2.0,-8.0], // Y
+        [ 0.0,-2.0,-2.0,-2.0,-2.0,-2.0,-2.0,-1.0,-2.0, 4.0, 2.0,-2.0, 2.0,-1.0,-1.0,-1.0, 0.0,-6.0,-2.0, 4.0,-2.0,-2.0,-1.0,-8.0], // V
+        [ 0.0,-1.0, 2.0, 3.0,-4.0, 1.0, 3.0, 0.0, 1.0,-2.0,-3.0, 1.0,-2.0,-4.0,-1.0, 0.0, 0.0,-5.0,-3.0,-2.0, 3.0, 2.0,-1.0,-8.0], // B
+        [ 0.0,-1.0, 1.0, 3.0,-5.0, 3.0, 3.0, 0.0, 2.0,-2.0,-3.0, 0.0,-2.0,-5.0, 0.0, 0.0,-1.0,-6.0,-4.0,-2.0, 2.0, 3.0,-1.0,-8.0], // Z
+        [ 0.0,-1.0, 0.0,-1.0,-3.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-1.0,-2.0,-1.0, 0.0, 0.0,-4.0,-2.0,-1.0,-1.0,-1.0,-1.0,-8.0], // X
+        [-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0,-8.0, 1.0], // *
+    ];
+
+    populate_protein_matrix(&chars, &values)
+}
+
+/// Build a case-insensitive lookup table from a substitution matrix's
+/// character alphabet and score table, following the same population logic
+/// as [`get_ednafull_matrix`].
+fn populate_protein_matrix(chars: &[char], values: &[[f32; 24]; 24]) -> HashMap<(char, char), f32> {
+    let mut matrix = HashMap::new();
+    for (i, &c1) in chars.iter().enumerate() {
+        for (j, &c2) in chars.iter().enumerate() {
+            matrix.insert((c1, c2), values[i][j]);
+            matrix.insert((c1.to_ascii_lowercase(), c2), values[i][j]);
+            matrix.insert((c1, c2.to_ascii_lowercase()), values[i][j]);
+            matrix.insert(
+                (c1.to_ascii_lowercase(), c2.to_ascii_lowercase()),
+                values[i][j],
+            );
+        }
+    }
+    matrix
+}
+
 /// Returns a list of available substitution matrix names.
 pub fn available_matrices() -> Vec<&'static str> {
-    vec!["EDNAFULL"]
+    vec!["EDNAFULL", "BLOSUM62", "PAM250"]
 }
 
 /// Get a substitution matrix by name.
 ///
 /// Supported matrix names:
 /// - "EDNAFULL"
+/// - "BLOSUM62"
+/// - "PAM250"
 pub fn get_matrix_by_name(name: &str) -> Option<HashMap<(char, char), f32>> {
     match name.to_uppercase().as_str() {
         "EDNAFULL" => Some(get_ednafull_matrix()),
+        "BLOSUM62" => Some(get_blosum62_matrix()),
+        "PAM250" => Some(get_pam250_matrix()),
         _ => None,
     }
 }
@@ -89,6 +197,11 @@ mod tests {
         assert_eq!(matrix.get(&('A', 'W')), Some(&1.0)); // W = A or T
         assert_eq!(matrix.get(&('A', 'R')), Some(&1.0)); // R = A or G
         assert_eq!(matrix.get(&('N', 'N')), Some(&-1.0)); // N = any
+
+        // RNA uracil is scored identically to T
+        assert_eq!(matrix.get(&('U', 'U')), Some(&5.0));
+        assert_eq!(matrix.get(&('U', 'T')), Some(&5.0));
+        assert_eq!(matrix.get(&('U', 'A')), Some(&-4.0));
     }
 
     #[test]
@@ -106,8 +219,8 @@ mod tests {
         let matrix = get_ednafull_matrix();
 
         // Matrix should be symmetric
-        for c1 in ['A', 'T', 'G', 'C', 'N'] {
-            for c2 in ['A', 'T', 'G', 'C', 'N'] {
+        for c1 in ['A', 'T', 'G', 'C', 'N', 'U'] {
+            for c2 in ['A', 'T', 'G', 'C', 'N', 'U'] {
                 assert_eq!(
                     matrix.get(&(c1, c2)),
                     matrix.get(&(c2, c1)),
@@ -123,6 +236,9 @@ mod tests {
     fn test_get_matrix_by_name() {
         assert!(get_matrix_by_name("EDNAFULL").is_some());
         assert!(get_matrix_by_name("ednafull").is_some());
+        assert!(get_matrix_by_name("BLOSUM62").is_some());
+        assert!(get_matrix_by_name("blosum62").is_some());
+        assert!(get_matrix_by_name("PAM250").is_some());
         assert!(get_matrix_by_name("NUC4.4").is_none());
         assert!(get_matrix_by_name("NUC44").is_none());
         assert!(get_matrix_by_name("UNKNOWN").is_none());
@@ -131,6 +247,75 @@ mod tests {
     #[test]
     fn test_available_matrices() {
         let matrices = available_matrices();
-        assert_eq!(matrices, vec!["EDNAFULL"]);
+        assert_eq!(matrices, vec!["EDNAFULL", "BLOSUM62", "PAM250"]);
+    }
+
+    #[test]
+    fn test_blosum62_basic_scores() {
+        let matrix = get_blosum62_matrix();
+
+        // Check match scores (diagonal)
+        assert_eq!(matrix.get(&('A', 'A')), Some(&4.0));
+        assert_eq!(matrix.get(&('W', 'W')), Some(&11.0));
+
+        // Conservative substitution scores higher than a divergent one
+        assert_eq!(matrix.get(&('I', 'L')), Some(&2.0));
+        assert_eq!(matrix.get(&('W', 'D')), Some(&-4.0));
+
+        // Ambiguity/stop codes are present
+        assert_eq!(matrix.get(&('B', 'D')), Some(&4.0));
+        assert_eq!(matrix.get(&('*', '*')), Some(&1.0));
+    }
+
+    #[test]
+    fn test_blosum62_case_insensitive() {
+        let matrix = get_blosum62_matrix();
+
+        assert_eq!(matrix.get(&('a', 'a')), Some(&4.0));
+        assert_eq!(matrix.get(&('A', 'a')), Some(&4.0));
+        assert_eq!(matrix.get(&('a', 'A')), Some(&4.0));
+    }
+
+    #[test]
+    fn test_blosum62_symmetry() {
+        let matrix = get_blosum62_matrix();
+
+        for c1 in ['A', 'R', 'N', 'D', 'C', 'W', 'Y', 'V', 'B', 'Z', 'X', '*'] {
+            for c2 in ['A', 'R', 'N', 'D', 'C', 'W', 'Y', 'V', 'B', 'Z', 'X', '*'] {
+                assert_eq!(
+                    matrix.get(&(c1, c2)),
+                    matrix.get(&(c2, c1)),
+                    "Matrix should be symmetric for ({}, {})",
+                    c1,
+                    c2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pam250_basic_scores() {
+        let matrix = get_pam250_matrix();
+
+        assert_eq!(matrix.get(&('A', 'A')), Some(&2.0));
+        assert_eq!(matrix.get(&('W', 'W')), Some(&17.0));
+        assert_eq!(matrix.get(&('*', '*')), Some(&1.0));
+    }
+
+    #[test]
+    fn test_pam250_symmetry() {
+        let matrix = get_pam250_matrix();
+
+        for c1 in ['A', 'R', 'N', 'D', 'C', 'W', 'Y', 'V', 'B', 'Z', 'X', '*'] {
+            for c2 in ['A', 'R', 'N', 'D', 'C', 'W', 'Y', 'V', 'B', 'Z', 'X', '*'] {
+                assert_eq!(
+                    matrix.get(&(c1, c2)),
+                    matrix.get(&(c2, c1)),
+                    "Matrix should be symmetric for ({}, {})",
+                    c1,
+                    c2
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,134 @@
+//! Block-shaded visualization of an alignment, computed on demand when
+//! `AlignmentInput::render` is set so callers that don't need it avoid the
+//! extra work.
+
+use crate::Alignment;
+use serde::Serialize;
+
+/// Glyph ramp for increasingly positive column scores, from no signal to
+/// strongest match.
+const POSITIVE_RAMP: &[char] = &[' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Glyph ramp for increasingly negative column scores (gaps, mismatches).
+const PENALTY_RAMP: &[char] = &[' ', '░', '▒', '▓', '█'];
+
+/// Pre-rendered visualization of an alignment: the two aligned sequence rows
+/// alongside a per-column Unicode block "shading" row, so a Typst template
+/// can embed a heatmap-like view of alignment quality without recomputing
+/// scores itself.
+#[derive(Serialize)]
+pub struct RenderedAlignment {
+    pub seq1_row: String,
+    pub seq2_row: String,
+    /// One glyph per column: the positive ramp for scores above zero,
+    /// scaled against the alignment's highest column score, or the penalty
+    /// ramp for scores below zero, scaled against its lowest
+    pub shading_row: String,
+}
+
+/// Maps a single column score to a ramp glyph, normalized against the
+/// alignment's own minimum (for penalties) and maximum (for positive scores).
+fn shading_glyph(score: f32, min_score: f32, max_score: f32) -> char {
+    if score >= 0.0 {
+        if max_score <= 0.0 {
+            return POSITIVE_RAMP[0];
+        }
+        let frac = (score / max_score).clamp(0.0, 1.0);
+        let idx = (frac * (POSITIVE_RAMP.len() - 1) as f32).round() as usize;
+        POSITIVE_RAMP[idx]
+    } else {
+        if min_score >= 0.0 {
+            return PENALTY_RAMP[0];
+        }
+        let frac = (score / min_score).clamp(0.0, 1.0);
+        let idx = (frac * (PENALTY_RAMP.len() - 1) as f32).round() as usize;
+        PENALTY_RAMP[idx]
+    }
+}
+
+/// Builds the block-shaded visualization for an already-computed alignment.
+pub fn render_alignment(alignment: &Alignment) -> RenderedAlignment {
+    let max_score = alignment
+        .column_scores
+        .iter()
+        .cloned()
+        .fold(f32::MIN, f32::max)
+        .max(0.0);
+    let min_score = alignment
+        .column_scores
+        .iter()
+        .cloned()
+        .fold(f32::MAX, f32::min)
+        .min(0.0);
+
+    let shading_row = alignment
+        .column_scores
+        .iter()
+        .map(|&score| shading_glyph(score, min_score, max_score))
+        .collect();
+
+    RenderedAlignment {
+        seq1_row: alignment.aligned_seq1.clone(),
+        seq2_row: alignment.aligned_seq2.clone(),
+        shading_row,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alignment_with_scores(column_scores: Vec<f32>) -> Alignment {
+        Alignment {
+            aligned_seq1: "A".repeat(column_scores.len()),
+            aligned_seq2: "A".repeat(column_scores.len()),
+            path: Vec::new(),
+            start_i: 0,
+            start_j: 0,
+            end_i: column_scores.len(),
+            end_j: column_scores.len(),
+            column_scores,
+            match_line: String::new(),
+            rendered: None,
+        }
+    }
+
+    #[test]
+    fn test_shading_row_length_matches_columns() {
+        let alignment = alignment_with_scores(vec![5.0, -4.0, 0.0, 2.5]);
+        let rendered = render_alignment(&alignment);
+        assert_eq!(rendered.shading_row.chars().count(), 4);
+        assert_eq!(rendered.seq1_row, alignment.aligned_seq1);
+        assert_eq!(rendered.seq2_row, alignment.aligned_seq2);
+    }
+
+    #[test]
+    fn test_best_positive_score_gets_full_block() {
+        let alignment = alignment_with_scores(vec![5.0, 2.5, -4.0]);
+        let rendered = render_alignment(&alignment);
+        let glyphs: Vec<char> = rendered.shading_row.chars().collect();
+        assert_eq!(glyphs[0], '█');
+    }
+
+    #[test]
+    fn test_worst_penalty_gets_full_block() {
+        let alignment = alignment_with_scores(vec![5.0, -4.0, -1.0]);
+        let rendered = render_alignment(&alignment);
+        let glyphs: Vec<char> = rendered.shading_row.chars().collect();
+        assert_eq!(glyphs[1], '█');
+    }
+
+    #[test]
+    fn test_zero_score_is_blank() {
+        let alignment = alignment_with_scores(vec![5.0, 0.0, -4.0]);
+        let rendered = render_alignment(&alignment);
+        let glyphs: Vec<char> = rendered.shading_row.chars().collect();
+        assert_eq!(glyphs[1], ' ');
+    }
+
+    #[test]
+    fn test_all_zero_scores_stay_blank() {
+        let alignment = alignment_with_scores(vec![0.0, 0.0, 0.0]);
+        let rendered = render_alignment(&alignment);
+        assert_eq!(rendered.shading_row, "   ");
+    }
+}